@@ -0,0 +1,15 @@
+use parser::string_table::StringTable;
+
+/// Shared state for a single compilation: right now just the
+/// `StringTable`, but this is the natural place to hang session-wide
+/// state (interned paths, diagnostics sink, ...) as the compiler grows.
+#[derive(Default)]
+pub struct CompileContext {
+	string_table: StringTable
+}
+
+impl CompileContext {
+	pub fn get_string_table(&self) -> &StringTable {
+		&self.string_table
+	}
+}
@@ -0,0 +1,70 @@
+use std::str;
+
+/// A byte-oriented cursor over a source string, with O(1) lookahead.
+/// Unlike a `Chars` iterator it never needs to cache a "current"
+/// character: `peek`/`peek_nth` re-derive whatever is at or ahead of the
+/// cursor on demand, without consuming it. Decoding a full `char` only
+/// happens when a non-ASCII byte is encountered -- the hot path of
+/// delimiters, operators, ASCII identifiers and digits never leaves the
+/// byte domain.
+pub struct Cursor<'input> {
+	bytes: &'input [u8],
+	pos: usize
+}
+
+impl<'input> Cursor<'input> {
+	pub fn new(content: &'input str) -> Cursor<'input> {
+		Cursor { bytes: content.as_bytes(), pos: 0 }
+	}
+
+	/// The current byte offset into the source.
+	pub fn pos(&self) -> usize {
+		self.pos
+	}
+
+	/// The character at the cursor without consuming it, or `'\0'` past
+	/// the end of input.
+	pub fn peek(&self) -> char {
+		self.peek_nth(0)
+	}
+
+	/// The character `n` positions past the cursor without consuming
+	/// anything, or `'\0'` past the end of input.
+	pub fn peek_nth(&self, n: usize) -> char {
+		let mut pos = self.pos;
+		for _ in 0 .. n {
+			let (_, len) = self.char_at(pos);
+			if len == 0 {
+				return '\0';
+			}
+			pos += len;
+		}
+		self.char_at(pos).0
+	}
+
+	/// Consumes and returns the character at the cursor, advancing by
+	/// its full UTF-8 width. Returns `'\0'` past the end of input, and
+	/// does not advance any further in that case.
+	pub fn bump(&mut self) -> char {
+		let (c, len) = self.char_at(self.pos);
+		self.pos += len;
+		c
+	}
+
+	/// Decodes the character starting at byte offset `pos`, with an
+	/// ASCII fast path that skips UTF-8 decoding entirely. Indexing
+	/// into `self.bytes` at a non-ASCII `pos` is always on a char
+	/// boundary: the cursor is built from a `&str` and only ever
+	/// advances by a whole char's `len_utf8()` at a time.
+	fn char_at(&self, pos: usize) -> (char, usize) {
+		match self.bytes.get(pos) {
+			None => ('\0', 0),
+			Some(&byte) if byte < 0x80 => (byte as char, 1),
+			Some(_) => {
+				let rest = unsafe { str::from_utf8_unchecked(&self.bytes[pos ..]) };
+				let c = rest.chars().next().expect("pos is on a char boundary");
+				(c, c.len_utf8())
+			}
+		}
+	}
+}
@@ -1,27 +1,73 @@
 use std::str::Chars;
-use std::rc::Rc;
-
-use util::is_any_of::*;
 
 use parser::token::*;
 use parser::token_stream::TokenStream;
-use parser::util::char_util::CharProperties;
 use parser::compile_context::CompileContext;
-use parser::string_table::StringTable;
+use parser::raw_lexer::{RawLexer, TokenKind};
 
 // This is the lexer implementation for the parser (that sadly doesn't exist yet).
 // I am fully aware of super-neat tools that can generate lexers and parser automatically,
 // however, I want to implement this to learn about Rust and because I also want full control
 // over things in my code base.
 // Besides that, ... I like the Rust's match expressions. :)
-// Keep in mind that this implementation isn't final as many things like scan_string_literal(...)
-// are still missing or are not completely implemented, yet (like scan_char_literal(...)).
+// The actual character-by-character scanning lives in `RawLexer`, which is
+// context-free (it only borrows a `&str`); this `Lexer` is a thin adapter
+// that tracks source position and interns identifier/literal text into
+// the `CompileContext`'s `StringTable`.
+
+/// A single position within the source: a byte offset paired with the
+/// 1-based line and column it corresponds to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pos {
+	pub byte: u32,
+	pub line: u32,
+	pub column: u32
+}
+
+/// A half-open `[start, end)` range of source positions.
+/// Attached to every token so that later diagnostics can report
+/// `file:line:col` locations and underline the offending range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+	pub start: Pos,
+	pub end: Pos
+}
+
+/// What went wrong while lexing a token that the lexer nonetheless
+/// recovered from, so a single pass can surface more than one error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LexErrorKind {
+	UnterminatedBlockComment,
+	EmptyCharLiteral,
+	TooManyCharsInCharLiteral,
+	InvalidUnicodeEscape,
+	InvalidEscape,
+	UnterminatedStringLiteral
+}
+
+/// A diagnostic attached to a token, pointing at the span it concerns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexError {
+	pub span: Span,
+	pub kind: LexErrorKind
+}
+
+/// A `Token` paired with the `Span` of source it was lexed from, plus
+/// an optional diagnostic if the lexer had to recover from malformed
+/// input while producing it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedToken {
+	pub token: Token,
+	pub span: Span,
+	pub error: Option<LexError>
+}
 
 pub struct Lexer<'input, 'ctx> {
 	context: &'ctx CompileContext,
-	input: Chars<'input>,
-	buffer: String,
-	cur_char: char
+	source: &'input str,
+	raw: RawLexer<'input>,
+	pos: Pos,
+	offset: u32
 }
 
 impl<'input, 'ctx> Lexer<'input, 'ctx> {
@@ -31,13 +77,14 @@ impl<'input, 'ctx> Lexer<'input, 'ctx> {
 	)
 		-> Lexer<'input, 'ctx>
 	{
-		let mut lexer = Lexer {
+		let source = iterator.as_str();
+		Lexer {
 			context: ctx,
-			input: iterator,
-			buffer: String::new(),
-			cur_char: '\0' };
-		lexer.consume();
-		lexer
+			source,
+			raw: RawLexer::new(source),
+			pos: Pos { byte: 0, line: 1, column: 1 },
+			offset: 0
+		}
 	}
 
 	pub fn new_from_str<'content: 'input>(
@@ -49,417 +96,118 @@ impl<'input, 'ctx> Lexer<'input, 'ctx> {
 		Lexer::new(ctx, content.chars())
 	}
 
-	/// Stores the current character into the buffer
-	/// and returns reference to self for method chaining
-	fn store(&mut self) -> &mut Self {
-		self.buffer.push(self.cur_char);
-		self
-	}
-
-	/// Consumes the next character unwraped and
-	/// returns reference to self for method chaining
-	fn consume(&mut self) -> &mut Self {
-		self.cur_char = self.input.next().unwrap_or('\0');
-		self
-	}
-
-	/// Returns the char from input which was read last
-	fn get(&self) -> char {
-		self.cur_char
-	}
-
-	/// Returns the given token, used as helper method
-	/// for method chaining in order to improve the code-flow
-	/// May be more important in future versions for managing
-	/// of source locations.
-	fn make(&self, token: Token) -> Token {
-		token
-	}
-
-	/// Clears all chars in the buffer for special tokens
-	/// and returns reference to self for method chaining
-	fn clear_buffer(&mut self) -> &mut Self {
-		self.buffer.clear();
-		self
-	}
-
-	/// Drains the content of this buffer by performing
-	/// a trial insertion at the context's StringTable.
-	/// This buffer is empty after this operation!
-	fn drain_buffer(&mut self) -> Rc<String> {
-		let rc = self.context.get_string_table().get_or_insert(&self.buffer);
-		self.clear_buffer();
-		rc
-	}
-
-	fn scan_line_comment(&mut self) -> Token {
-		assert_eq!(self.get(), '/');
-		self.skip_while(|c| c.is_none_of(&['\n','\0']));
-		self.consume();
-		self.make(Token::Comment)
-	}
-
-	fn scan_multi_line_comment(&mut self) -> Token {
-		assert_eq!(self.get(), '*');
-		self.consume();
-		loop {
-			match self.get() {
-				'*' => match self.consume().get() {
-					'/' => return self.consume().make(Token::Comment),
-					'*' => continue,
-					_   => self.consume()
-				},
-				'\0' => return self.make(Token::Error),
-				_ => self.consume()
-			};
-		}
-	}
-
-	fn scan_identifier(&mut self) -> Token {
-		assert!(self.get().is_alpha());
-		self.store_while(|c| c.is_alpha_numeral() || c == '_');
-		let drained = self.drain_buffer();
-		self.make(Token::Identifier(drained))
-
-		// omg this doesn't work because the borrow-checker
-		// can not handle situations like this properly at the moment
-		// self.make(Token::Identifier(self.drain_buffer()))
-	}
-
-	fn scan_char_literal(&mut self) -> Token {
-		assert_eq!(self.get(), '\'');
-		match self.consume().get() {
-			/* error: empty character literal */
-			'\'' => self.make(Token::Error),
-
-			/* escape characters */
-			'\\' => match self.store().consume().get() {
-				/* special escape characters */
-				'n'  |
-				't'  |
-				'r'  |
-				'\\' |
-				'\'' => match self.store().consume().get() {
-					'\'' => {
-						self.consume();
-						let drained = self.drain_buffer();
-						self.make(Token::Literal(LiteralToken::Char(drained)))
-					},
-					_ => self.make(Token::Error)
+	/// Advances the running line/column/byte position by the characters
+	/// of `text`, normalizing `'\r\n'` and a lone `'\r'` into a single
+	/// newline -- line/column bookkeeping that `RawLexer` does not do,
+	/// since it only measures token lengths in bytes.
+	fn advance_pos(&mut self, text: &str) {
+		let mut chars = text.chars().peekable();
+		while let Some(c) = chars.next() {
+			match c {
+				'\r' => {
+					self.pos.byte += 1;
+					if chars.peek() == Some(&'\n') {
+						chars.next();
+						self.pos.byte += 1;
+					}
+					self.pos.line += 1;
+					self.pos.column = 1;
 				},
-
-				/* hex-code unicode followed by two hex-digits */
-				'x' => match self.store().consume().get() {
-					/* error: no hex-digits provided */
-					'\'' => self.make(Token::Error),
-
-					/* valid unicode starting code-point */
-					'0' ... '7' => match self.store().consume().get() {
-						/* error: just one unicode code-point given */
-						'\'' => self.make(Token::Error),
-
-						/* valid unicode 2nd code-point given */
-						'0' ... '9' |
-						'a' ... 'f' |
-						'A' ... 'F' => match self.store().consume().get() {
-							/* valid closed unicode char literal */
-							'\'' => {
-								let drained = self.drain_buffer();
-								self.make(Token::Literal(
-									LiteralToken::Char(drained)))
-							},
-							/* error: has to close after two hex-digits */
-							_ => self.make(Token::Error)
-						},
-
-						/* error: invalid 2nd code-point */
-						_ => self.make(Token::Error)
-					},
-
-					/* invalid starting points for unicode */
-					'8' ... '9' |
-					'a' ... 'f' |
-					'A' ... 'F' => self.make(Token::Error),
-
-					/* anything else invalid */
-					_ => self.make(Token::Error)
-				},
-
-				/* uni-code up to 6 hex-digits (TODO) */
-				'u' => match self.store().consume().get() {
-					_ => self.make(Token::Error)
-				},
-
-				/* no valid escape character read */
-				_ => self.make(Token::Error)
-			},
-
-			/* normal ascii charater literal */
-			_ => match self.store().consume().get() {
-				'\'' => {
-					let drained = self.drain_buffer();
-					self.consume().make(Token::Literal(
-						LiteralToken::Char(drained)))
+				'\n' => {
+					self.pos.byte += 1;
+					self.pos.line += 1;
+					self.pos.column = 1;
 				},
-				_ => self.make(Token::Error) // more than one code-point in character literal
+				c => {
+					self.pos.byte += c.len_utf8() as u32;
+					self.pos.column += 1;
+				}
 			}
 		}
 	}
 
-	fn scan_string_literal(&mut self) -> Token {
-		self.make(Token::Error)
-	}
-
-	fn scan_integral_literal_suffix(&mut self) -> Token {
-		self.store_while(|c| c.is_alpha_numeral());
-		let drained = self.drain_buffer();
-		self.make(Token::Literal(
-			LiteralToken::Integer(drained)))
-	}
-
-	fn scan_binary_literal(&mut self) -> Token {
-		assert_eq!(self.get(), 'b');
-		self.store().consume();
-		self.store_while(|c| c.is_binary_numeral() || c == '_');
-		// self.scan_integral_literal_suffix()
-		let drained = self.drain_buffer();
-		self.make(Token::Literal(
-			LiteralToken::Integer(drained)))
-	}
-
-	fn scan_octal_literal(&mut self) -> Token {
-		assert_eq!(self.get(), 'o');
-		self.store().consume();
-		self.store_while(|c| c.is_octal_numeral() || c == '_');
-		// self.scan_integral_literal_suffix()
-		let drained = self.drain_buffer();
-		self.make(Token::Literal(
-			LiteralToken::Integer(drained)))
-	}
-
-	fn scan_hexdec_literal(&mut self) -> Token {
-		assert_eq!(self.get(), 'x');
-		self.store().consume();
-		self.store_while(|c| c.is_hexdec_numeral() || c == '_');
-		// self.scan_integral_literal_suffix()
-		let drained = self.drain_buffer();
-		self.make(Token::Literal(
-			LiteralToken::Integer(drained)))
-	}
-
-	fn scan_decimal_literal(&mut self) -> Token {
-		assert!(self.get().is_decimal_numeral() || self.get() == '_');
-		self.store_while(|c| c.is_decimal_numeral() || c == '_');
-		match self.get() {
-			'.' => self.scan_float_literal(),
-			_ => {
-				let drained = self.drain_buffer();
-				self.make(Token::Literal(
-					LiteralToken::Integer(drained)))
+	/// Rebuilds a rich `Token` from a `RawToken`'s kind and the slice of
+	/// source it spans, interning identifier and literal text into the
+	/// `CompileContext`'s `StringTable` along the way.
+	fn build_token(&self, kind: TokenKind, text: &str) -> Token {
+		match kind {
+			TokenKind::Whitespace => Token::Whitespace,
+			TokenKind::Comment => Token::Comment,
+			TokenKind::Error => Token::Error,
+			TokenKind::EndOfFile => Token::EndOfFile,
+
+			TokenKind::OpenDelim(delim) => Token::OpenDelim(delim),
+			TokenKind::CloseDelim(delim) => Token::CloseDelim(delim),
+
+			TokenKind::Question => Token::Question,
+			TokenKind::SemiColon => Token::SemiColon,
+			TokenKind::Comma => Token::Comma,
+			TokenKind::Underscore => Token::Underscore,
+
+			TokenKind::Dot => Token::Dot,
+			TokenKind::DotDot => Token::DotDot,
+			TokenKind::DotDotDot => Token::DotDotDot,
+			TokenKind::Arrow => Token::Arrow,
+			TokenKind::FatArrow => Token::FatArrow,
+
+			TokenKind::Exclamation => Token::Exclamation,
+			TokenKind::Eq => Token::Eq,
+
+			TokenKind::BinOp(op) => Token::BinOp(op),
+			TokenKind::BinOpEq(op) => Token::BinOpEq(op),
+			TokenKind::RelOp(op) => Token::RelOp(op),
+			TokenKind::LogicalOp(op) => Token::LogicalOp(op),
+
+			TokenKind::Identifier =>
+				Token::Identifier(self.context.get_string_table().get_or_insert(text)),
+
+			TokenKind::Literal { kind, body_start, body_end, suffix_start } => {
+				let body_start = body_start as usize;
+				let body_end = body_end as usize;
+				let suffix_start = suffix_start as usize;
+				let symbol = self.context.get_string_table().get_or_insert(&text[body_start .. body_end]);
+				let suffix = if suffix_start < text.len() {
+					Some(self.context.get_string_table().get_or_insert(&text[suffix_start ..]))
+				} else {
+					None
+				};
+				Token::Literal(Lit { kind, symbol, suffix })
 			}
 		}
 	}
-
-	fn scan_float_literal(&mut self) -> Token {
-		assert_eq!(self.get(), '.');
-		Token::EndOfFile
-	}
-
-	fn scan_number_literal(&mut self) -> Token {
-		assert!(self.get().is_decimal_numeral());
-		match self.get() {
-			'0' => match self.store().consume().get() {
-				'b' => self.scan_binary_literal(),
-				'o' => self.scan_octal_literal(),
-				'x' => self.scan_hexdec_literal(),
-				'.' => self.scan_float_literal(),
-
-				/* dec number literals cannot start with '0' */
-				'0' ... '9' | '_' => self.scan_decimal_literal(),
-
-				/* literal number suffix, e.g. 0i32 */
-				c if c.is_alpha() => self.scan_integral_literal_suffix(),
-
-				/* */
-				_ => self.make(Token::Error)
-			},
-
-			/* decimal number literal */
-			'1' ... '9' => self.scan_decimal_literal(),
-
-			_ => unreachable!()
-		}
-	}
-
-	/// Stores all characters from input as long as they fullfill the given predicate
-	/// and returns reference to self for method chaining
-	fn store_while<P>(&mut self, predicate: P) -> &mut Self
-		where P: Fn(char) -> bool
-	{
-		while predicate(self.get()) {
-			self.store().consume();
-		}
-		self
-	}
-
-	/// Skips all characters from input as long as they fullfill the given predicate
-	/// and returns reference to self for method chaining
-	fn skip_while<P>(&mut self, predicate: P) -> &mut Self
-		where P: Fn(char) -> bool
-	{
-		while predicate(self.get()) {
-			self.consume();
-		}
-		self
-	}
 }
 
 impl<'input, 'ctx> TokenStream for Lexer<'input, 'ctx> {
-	fn next_token(&mut self) -> Token {
-		self.clear_buffer();
-		match self.get() {
-			/* Skip whitespace */
-			c if c.is_whitespace() => {
-				self.skip_while(|c| c.is_whitespace());
-				self.make(Token::Whitespace)
-			},
-
-			/* Opening delimiters */
-			'(' => self.consume().make(Token::OpenDelim(DelimitToken::Paren)),
-			'[' => self.consume().make(Token::OpenDelim(DelimitToken::Bracket)),
-			'{' => self.consume().make(Token::OpenDelim(DelimitToken::Brace)),
-
-			/* Opening delimiters */
-			')' => self.consume().make(Token::CloseDelim(DelimitToken::Paren)),
-			']' => self.consume().make(Token::CloseDelim(DelimitToken::Bracket)),
-			'}' => self.consume().make(Token::CloseDelim(DelimitToken::Brace)),
-
-			/* Special tokens which aren't the beginning
-			   of any other token */
-			'?' => self.consume().make(Token::Question),
-			';' => self.consume().make(Token::SemiColon),
-			',' => self.consume().make(Token::Comma),
-			'_' => self.consume().make(Token::Underscore),
-
-			/* Dot, DotDot and DotDotDot tokens */
-			'.' => match self.consume().get() {
-				'.' => match self.consume().get() {
-					'.' => self.consume().make(Token::DotDotDot),
-					_   => self.make(Token::DotDot)
-				},
-				_ => self.make(Token::Dot)
-			},
-
-			/* Tokens starting with '+' */
-			'+' => match self.consume().get() {
-				'=' => self.consume().make(Token::BinOpEq(BinOpToken::Plus)),
-				_   => self.make(Token::BinOp(BinOpToken::Plus))
-			},
-
-			/* Tokens starting with '-' */
-			'-' => match self.consume().get() {
-				'=' => self.consume().make(Token::BinOpEq(BinOpToken::Minus)),
-				'>' => self.consume().make(Token::Arrow),
-				_   => self.make(Token::BinOp(BinOpToken::Minus))
-			},
-
-			/* Tokens starting with '*' */
-			'*' => match self.consume().get() {
-				'=' => self.consume().make(Token::BinOpEq(BinOpToken::Star)),
-				_   => self.make(Token::BinOp(BinOpToken::Star))
-			},
-
-			/* Tokens starting with '/' */
-			'/' => match self.consume().get() {
-				'=' => self.consume().make(Token::BinOpEq(BinOpToken::Slash)),
-				'/' => self.scan_line_comment(),
-				'*' => self.scan_multi_line_comment(),
-				_ => self.make(Token::BinOp(BinOpToken::Slash))
-			},
-
-			/* Tokens starting with '%' */
-			'%' => match self.consume().get() {
-				'=' => self.consume().make(Token::BinOpEq(BinOpToken::Percent)),
-				_   => self.make(Token::BinOp(BinOpToken::Percent))
-			},
-
-			/* Tokens starting with '^' */
-			'^' => match self.consume().get() {
-				'=' => self.consume().make(Token::BinOpEq(BinOpToken::Caret)),
-				_   => self.make(Token::BinOp(BinOpToken::Caret))
-			},
-
-			/* Tokens starting with '!' */
-			'!' => match self.consume().get() {
-				'=' => self.consume().make(Token::RelOp(RelOpToken::NotEq)),
-				_   => self.make(Token::Exclamation)
-			},
-
-			/* Tokens starting with '=' */
-			'=' => match self.consume().get() {
-				'>' => self.consume().make(Token::FatArrow),
-				'=' => self.consume().make(Token::RelOp(RelOpToken::EqEq)),
-				_   => self.make(Token::Eq)
-			},
-
-			/* Tokens starting with '&' */
-			'&' => match self.consume().get() {
-				'&' => self.consume().make(Token::LogicalOp(LogicalOpToken::AndAnd)),
-				'=' => self.consume().make(Token::BinOpEq(BinOpToken::And)),
-				_   => self.make(Token::BinOp(BinOpToken::And))
-			},
-
-			/* Tokens starting with '|' */
-			'|' => match self.consume().get() {
-				'|' => self.consume().make(Token::LogicalOp(LogicalOpToken::OrOr)),
-				'=' => self.consume().make(Token::BinOpEq(BinOpToken::Or)),
-				_   => self.make(Token::BinOp(BinOpToken::Or))
-			},
-
-			/* Tokens starting with '<' */
-			'<' => match self.consume().get() {
-				'<' => match self.consume().get() {
-					'=' => self.consume().make(Token::BinOpEq(BinOpToken::Shl)),
-					_   => self.make(Token::BinOp(BinOpToken::Shl))
-				},
-				'=' => self.consume().make(Token::RelOp(RelOpToken::LessEq)),
-				_   => self.make(Token::RelOp(RelOpToken::LessThan))
-			},
-
-			/* Tokens starting with '>' */
-			'>' => match self.consume().get() {
-				'>' => match self.consume().get() {
-					'=' => self.consume().make(Token::BinOpEq(BinOpToken::Shr)),
-					_   => self.make(Token::BinOp(BinOpToken::Shr))
-				},
-				'=' => self.consume().make(Token::RelOp(RelOpToken::GreaterEq)),
-				_   => self.make(Token::RelOp(RelOpToken::GreaterThan))
-			},
+	fn next_token(&mut self) -> SpannedToken {
+		let token_start = self.pos;
+		let raw_token = self.raw.next_token();
 
-			/* Char and string literals */
-			'\'' => self.scan_char_literal(),
-			'\"' => self.scan_string_literal(),
+		let start = self.offset as usize;
+		let end = start + raw_token.len as usize;
+		let text = &self.source[start .. end];
 
-			/* Integer- and float literals and identifiers */
-			c if c.is_decimal_numeral() => self.scan_number_literal(),
+		self.advance_pos(text);
+		self.offset = end as u32;
 
-			/* Identifiers and keywords */
-			c if c.is_alpha() => self.scan_identifier(),
+		let span = Span { start: token_start, end: self.pos };
+		let token = self.build_token(raw_token.kind, text);
 
-			/* When end of iterator has been reached */
-			_ => self.make(Token::EndOfFile)
+		SpannedToken {
+			token,
+			span,
+			error: raw_token.error.map(|kind| LexError { span, kind })
 		}
 	}
 }
 
 impl<'input, 'ctx> Iterator for Lexer<'input, 'ctx> {
-	type Item = Token;
+	type Item = SpannedToken;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		let token = self.next_token();
-		match token {
+		let spanned = self.next_token();
+		match spanned.token {
 			Token::EndOfFile => None,
-			_                => Some(token)
+			_                => Some(spanned)
 		}
 	}
 }
@@ -469,18 +217,16 @@ mod tests {
 	use std::rc::Rc;
 
 	use super::*;
-	use super::super::token::*;
-	use super::super::compile_context::CompileContext;
 
 	#[test]
 	fn simple_tokens() {
 		let solution = vec![
 			Token::OpenDelim(DelimitToken::Paren),
 			Token::CloseDelim(DelimitToken::Paren),
-			
+
 			Token::OpenDelim(DelimitToken::Bracket),
 			Token::CloseDelim(DelimitToken::Bracket),
-			
+
 			Token::OpenDelim(DelimitToken::Brace),
 			Token::CloseDelim(DelimitToken::Brace),
 
@@ -493,7 +239,7 @@ mod tests {
 		let ctx   = CompileContext::default();
 		let lexer = Lexer::new_from_str(&ctx, "()[]{}?;,");
 		for zipped in solution.into_iter().zip(lexer) {
-			assert_eq!(zipped.0, zipped.1);
+			assert_eq!(zipped.0, zipped.1.token);
 		}
 	}
 
@@ -512,7 +258,7 @@ mod tests {
 			/*Ignored new line!\nBlub!\nSee?*/
 			/****multiple stars don't hurt****/");
 		for zipped in solution.into_iter().zip(lexer) {
-			assert_eq!(zipped.0, zipped.1);
+			assert_eq!(zipped.0, zipped.1.token);
 		}
 	}
 
@@ -528,25 +274,28 @@ mod tests {
 		let ctx   = CompileContext::default();
 		let lexer = Lexer::new_from_str(&ctx, "..\t.../*Useless comment*/.");
 		for zipped in solution.into_iter().zip(lexer) {
-			assert_eq!(zipped.0, zipped.1);
+			assert_eq!(zipped.0, zipped.1.token);
 		}
 	}
 
 	#[test]
 	fn simple_char_literal() {
+		// `Lit::symbol` holds the value text as written in the source (see
+		// its doc comment), same as the string-literal tests below -- the
+		// lexer doesn't decode escapes, that's the parser's job.
 		let solution = vec![
 			Token::char_literal_from_str("c"),
 			Token::Whitespace,
-			Token::char_literal_from_str("\n"),
+			Token::char_literal_from_str("\\n"),
 			Token::Whitespace,
-			Token::char_literal_from_str("\t"),
+			Token::char_literal_from_str("\\t"),
 			Token::Whitespace,
-			Token::char_literal_from_str("\x7F")
+			Token::char_literal_from_str("\\x7F")
 		];
 		let ctx   = CompileContext::default();
 		let lexer = Lexer::new_from_str(&ctx, r"'c' '\n' '\t' '\x7F'");
 		for zipped in solution.into_iter().zip(lexer) {
-			assert_eq!(zipped.0, zipped.1);
+			assert_eq!(zipped.0, zipped.1.token);
 		}
 	}
 
@@ -556,7 +305,7 @@ mod tests {
 		let ctx   = CompileContext::default();
 		let lexer = Lexer::new_from_str(&ctx, " \t\r\n");
 		for zipped in solution.into_iter().zip(lexer) {
-			assert_eq!(zipped.0, zipped.1);
+			assert_eq!(zipped.0, zipped.1.token);
 		}
 	}
 
@@ -578,7 +327,7 @@ mod tests {
 			 0xFF_AE_03_95
 			 987654321");
 		for zipped in solution.into_iter().zip(lexer) {
-			assert_eq!(zipped.0, zipped.1);
+			assert_eq!(zipped.0, zipped.1.token);
 		}
 	}
 
@@ -604,7 +353,27 @@ mod tests {
 			 13.37
 			 0.00001");
 		for zipped in solution.into_iter().zip(lexer) {
-			assert_eq!(zipped.0, zipped.1);
+			assert_eq!(zipped.0, zipped.1.token);
+		}
+	}
+
+	#[test]
+	fn literal_suffixes() {
+		let solution = vec![
+			Token::Literal(Lit { kind: LitKind::Integer, symbol: Rc::new("1".to_string()), suffix: Some(Rc::new("u32".to_string())) }),
+			Token::Whitespace,
+			Token::Literal(Lit { kind: LitKind::Integer, symbol: Rc::new("0xFF_".to_string()), suffix: Some(Rc::new("u8".to_string())) }),
+			Token::Whitespace,
+			Token::Literal(Lit { kind: LitKind::Float, symbol: Rc::new("3.14".to_string()), suffix: Some(Rc::new("f64".to_string())) }),
+			Token::Whitespace,
+			Token::Literal(Lit { kind: LitKind::Float, symbol: Rc::new("1e10".to_string()), suffix: Some(Rc::new("f32".to_string())) }),
+			Token::Whitespace,
+			Token::Literal(Lit { kind: LitKind::Char, symbol: Rc::new("c".to_string()), suffix: Some(Rc::new("baz".to_string())) })
+		];
+		let ctx   = CompileContext::default();
+		let lexer = Lexer::new_from_str(&ctx, "1u32 0xFF_u8 3.14f64 1e10f32 'c'baz");
+		for zipped in solution.into_iter().zip(lexer) {
+			assert_eq!(zipped.0, zipped.1.token);
 		}
 	}
 
@@ -631,7 +400,98 @@ mod tests {
 			 underscores_at_the_end__
 			 with_n0m3r5");
 		for zipped in solution.into_iter().zip(lexer) {
-			assert_eq!(zipped.0, zipped.1);
+			assert_eq!(zipped.0, zipped.1.token);
+		}
+	}
+
+	#[test]
+	fn tracks_line_and_column() {
+		let ctx = CompileContext::default();
+		let lexer = Lexer::new_from_str(&ctx, "ab\ncd\r\nef\rgh");
+		let tokens: Vec<_> = lexer.collect();
+
+		// "ab"
+		assert_eq!(tokens[0].span.start, Pos { byte: 0, line: 1, column: 1 });
+		assert_eq!(tokens[0].span.end,   Pos { byte: 2, line: 1, column: 3 });
+
+		// "cd", after the '\n' (tokens[1] is the whitespace in between)
+		assert_eq!(tokens[2].span.start, Pos { byte: 3, line: 2, column: 1 });
+		assert_eq!(tokens[2].span.end,   Pos { byte: 5, line: 2, column: 3 });
+
+		// "ef", after the '\r\n' (counted as a single newline)
+		assert_eq!(tokens[4].span.start, Pos { byte: 7, line: 3, column: 1 });
+		assert_eq!(tokens[4].span.end,   Pos { byte: 9, line: 3, column: 3 });
+
+		// "gh", after the lone '\r' (also counted as a single newline)
+		assert_eq!(tokens[6].span.start, Pos { byte: 10, line: 4, column: 1 });
+		assert_eq!(tokens[6].span.end,   Pos { byte: 12, line: 4, column: 3 });
+	}
+
+	#[test]
+	fn recovers_from_malformed_char_literals() {
+		let ctx   = CompileContext::default();
+		let lexer = Lexer::new_from_str(&ctx, "'' 'ab' 'c'");
+		let tokens: Vec<_> = lexer.collect();
+
+		assert_eq!(tokens[0].error.map(|e| e.kind), Some(LexErrorKind::EmptyCharLiteral));
+		assert_eq!(tokens[2].error.map(|e| e.kind), Some(LexErrorKind::TooManyCharsInCharLiteral));
+
+		// lexing resumes normally afterwards instead of wedging
+		assert_eq!(tokens[4].token, Token::char_literal_from_str("c"));
+		assert_eq!(tokens[4].error, None);
+	}
+
+	#[test]
+	fn simple_string_literal() {
+		let solution = vec![
+			Token::string_literal_from_str("hello, world!"),
+			Token::Whitespace,
+			Token::string_literal_from_str("line\\nbreak\\t\\\"quoted\\\"")
+		];
+		let ctx   = CompileContext::default();
+		let lexer = Lexer::new_from_str(&ctx, r#""hello, world!" "line\nbreak\t\"quoted\"""#);
+		for zipped in solution.into_iter().zip(lexer) {
+			assert_eq!(zipped.0, zipped.1.token);
+		}
+	}
+
+	#[test]
+	fn raw_and_byte_string_literals() {
+		let solution = vec![
+			Token::Literal(Lit { kind: LitKind::StrRaw(0), symbol: Rc::new(r"no \escapes here".to_string()), suffix: None }),
+			Token::Whitespace,
+			Token::Literal(Lit { kind: LitKind::StrRaw(2), symbol: Rc::new(r#"has a "quote" inside"#.to_string()), suffix: None }),
+			Token::Whitespace,
+			Token::Literal(Lit { kind: LitKind::ByteStr, symbol: Rc::new("bytes".to_string()), suffix: None }),
+			Token::Whitespace,
+			Token::Literal(Lit { kind: LitKind::ByteStrRaw(1), symbol: Rc::new(r"raw bytes".to_string()), suffix: None })
+		];
+		let ctx   = CompileContext::default();
+		let lexer = Lexer::new_from_str(&ctx,
+			r###"r"no \escapes here" r##"has a "quote" inside"## b"bytes" br#"raw bytes"#"###);
+		for zipped in solution.into_iter().zip(lexer) {
+			assert_eq!(zipped.0, zipped.1.token);
 		}
 	}
+
+	#[test]
+	fn recovers_from_malformed_string_literals() {
+		let ctx   = CompileContext::default();
+		let lexer = Lexer::new_from_str(&ctx, "\"unterminated\nr\"also unterminated");
+		let tokens: Vec<_> = lexer.collect();
+
+		assert_eq!(tokens[0].error.map(|e| e.kind), Some(LexErrorKind::UnterminatedStringLiteral));
+		assert_eq!(tokens[2].error.map(|e| e.kind), Some(LexErrorKind::UnterminatedStringLiteral));
+	}
+
+	#[test]
+	fn recovers_from_unterminated_block_comment() {
+		let ctx   = CompileContext::default();
+		let lexer = Lexer::new_from_str(&ctx, "/* never closed");
+		let tokens: Vec<_> = lexer.collect();
+
+		assert_eq!(tokens.len(), 1);
+		assert_eq!(tokens[0].token, Token::Comment);
+		assert_eq!(tokens[0].error.map(|e| e.kind), Some(LexErrorKind::UnterminatedBlockComment));
+	}
 }
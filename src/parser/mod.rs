@@ -0,0 +1,8 @@
+pub mod compile_context;
+pub mod cursor;
+pub mod lexer;
+pub mod raw_lexer;
+pub mod string_table;
+pub mod token;
+pub mod token_stream;
+pub mod util;
@@ -0,0 +1,734 @@
+use util::is_any_of::*;
+
+use parser::cursor::Cursor;
+use parser::token::*;
+use parser::util::char_util::CharProperties;
+use parser::lexer::LexErrorKind;
+
+/// The lightweight counterpart of `Token`: just enough shape to rebuild a
+/// rich `Token` from the slice of source it covers, with no interning and
+/// no `Rc` anywhere. A `Literal`'s offsets are all relative to the start
+/// of the token: `body_start`/`body_end` delimit the literal's value,
+/// skipping any prefix (`b`, `r`, `#`s) and opening/closing quote, and
+/// `suffix_start` is where the optional suffix begins; it equals the
+/// token's length when there is no suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+	Whitespace,
+	Comment,
+	Error,
+	EndOfFile,
+
+	OpenDelim(DelimitToken),
+	CloseDelim(DelimitToken),
+
+	Question,
+	SemiColon,
+	Comma,
+	Underscore,
+
+	Dot,
+	DotDot,
+	DotDotDot,
+	Arrow,
+	FatArrow,
+
+	Exclamation,
+	Eq,
+
+	BinOp(BinOpToken),
+	BinOpEq(BinOpToken),
+	RelOp(RelOpToken),
+	LogicalOp(LogicalOpToken),
+
+	Identifier,
+	Literal { kind: LitKind, body_start: u32, body_end: u32, suffix_start: u32 }
+}
+
+/// Where a literal's value starts, relative to the start of the token,
+/// given its `kind` -- the number of bytes taken up by any `b`/`r`
+/// prefix, `#` run and opening quote. Zero for kinds with no delimiter
+/// (integers, floats) since their prefix (e.g. `0x`) is part of the
+/// value itself.
+fn literal_body_start(kind: LitKind) -> u32 {
+	match kind {
+		LitKind::Char | LitKind::Str => 1,
+		LitKind::ByteStr => 2,
+		LitKind::StrRaw(hashes) => 2 + hashes as u32,
+		LitKind::ByteStrRaw(hashes) => 3 + hashes as u32,
+		_ => 0
+	}
+}
+
+/// A `TokenKind` together with the number of bytes of source it spans and
+/// whether the lexer had to recover from malformed input while scanning
+/// it. Carries no span or interned text -- both require state (a running
+/// position, a `StringTable`) that belongs to a whole compilation, not to
+/// context-free tokenization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawToken {
+	pub kind: TokenKind,
+	pub len: u32,
+	pub error: Option<LexErrorKind>
+}
+
+/// A context-free tokenizer: it borrows only a `&str` and knows nothing
+/// about `CompileContext` or `StringTable`. It does no interning and no
+/// span tracking, which makes it reusable by tooling (syntax highlighters,
+/// formatters, doc tools) that only need raw token boundaries. `Lexer` is
+/// a thin adapter built on top of it.
+pub struct RawLexer<'input> {
+	cursor: Cursor<'input>,
+	token_start: usize
+}
+
+impl<'input> RawLexer<'input> {
+	pub fn new(content: &'input str) -> RawLexer<'input> {
+		RawLexer { cursor: Cursor::new(content), token_start: 0 }
+	}
+
+	/// Consumes the character at the cursor and returns a reference to
+	/// self for method chaining.
+	fn bump(&mut self) -> &mut Self {
+		self.cursor.bump();
+		self
+	}
+
+	/// Peeks the character at the cursor without consuming it.
+	fn get(&self) -> char {
+		self.cursor.peek()
+	}
+
+	/// Peeks the character `n + 1` positions past the cursor (so
+	/// `peek_nth(0)` is the one right after it) without consuming
+	/// anything, returning `'\0'` past the end of input.
+	fn peek_nth(&self, n: usize) -> char {
+		self.cursor.peek_nth(n + 1)
+	}
+
+	/// The number of bytes consumed since `next_token` started scanning.
+	fn len(&self) -> u32 {
+		(self.cursor.pos() - self.token_start) as u32
+	}
+
+	fn make(&self, kind: TokenKind) -> RawToken {
+		RawToken { kind, len: self.len(), error: None }
+	}
+
+	fn make_err(&self, kind: TokenKind, err: LexErrorKind) -> RawToken {
+		RawToken { kind, len: self.len(), error: Some(err) }
+	}
+
+	/// Consumes all characters from input as long as they fulfill the
+	/// given predicate and returns a reference to self for method
+	/// chaining.
+	fn consume_while<P>(&mut self, predicate: P) -> &mut Self
+		where P: Fn(char) -> bool
+	{
+		while predicate(self.get()) {
+			self.bump();
+		}
+		self
+	}
+
+	fn scan_line_comment(&mut self) -> RawToken {
+		assert_eq!(self.get(), '/');
+		self.consume_while(|c| c.is_none_of(&['\n', '\0']));
+		self.bump();
+		self.make(TokenKind::Comment)
+	}
+
+	fn scan_multi_line_comment(&mut self) -> RawToken {
+		assert_eq!(self.get(), '*');
+		self.bump();
+		loop {
+			match self.get() {
+				'*' => match self.bump().get() {
+					'/' => return self.bump().make(TokenKind::Comment),
+					'*' => continue,
+					_   => self.bump()
+				},
+				'\0' => return self.make_err(TokenKind::Comment, LexErrorKind::UnterminatedBlockComment),
+				_ => self.bump()
+			};
+		}
+	}
+
+	fn scan_identifier(&mut self) -> RawToken {
+		assert!(self.get().is_alpha());
+		self.consume_while(|c| c.is_alpha_numeral() || c == '_');
+		self.make(TokenKind::Identifier)
+	}
+
+	/// Recovers from a malformed char literal by consuming up to the
+	/// closing `'` or a newline, whichever comes first, and emitting
+	/// whatever was scanned so far as a `Char` literal tagged with the
+	/// given diagnostic, instead of wedging on a bare `TokenKind::Error`.
+	fn recover_char_literal(&mut self, kind: LexErrorKind) -> RawToken {
+		self.consume_while(|c| c.is_none_of(&['\'', '\n', '\0']));
+		let body_end = self.len();
+		if self.get() == '\'' {
+			self.bump();
+		}
+		let suffix_start = self.len();
+		self.make_err(TokenKind::Literal {
+			kind: LitKind::Char,
+			body_start: literal_body_start(LitKind::Char),
+			body_end,
+			suffix_start
+		}, kind)
+	}
+
+	/// Consumes the closing `'` of a well-formed char literal along with
+	/// its optional suffix.
+	fn close_char_literal(&mut self) -> RawToken {
+		let body_end = self.len();
+		self.bump();
+		let suffix_start = self.len();
+		self.scan_suffix();
+		self.make(TokenKind::Literal {
+			kind: LitKind::Char,
+			body_start: literal_body_start(LitKind::Char),
+			body_end,
+			suffix_start
+		})
+	}
+
+	fn scan_char_literal(&mut self) -> RawToken {
+		assert_eq!(self.get(), '\'');
+		match self.bump().get() {
+			/* error: empty character literal */
+			'\'' => self.recover_char_literal(LexErrorKind::EmptyCharLiteral),
+
+			/* escape characters */
+			'\\' => match self.bump().get() {
+				/* special escape characters */
+				'n'  |
+				't'  |
+				'r'  |
+				'\\' |
+				'\'' => match self.bump().get() {
+					'\'' => self.close_char_literal(),
+					_ => self.recover_char_literal(LexErrorKind::TooManyCharsInCharLiteral)
+				},
+
+				/* hex-code unicode followed by two hex-digits */
+				'x' => match self.bump().get() {
+					/* error: no hex-digits provided */
+					'\'' => self.recover_char_literal(LexErrorKind::InvalidUnicodeEscape),
+
+					/* valid unicode starting code-point */
+					'0' ... '7' => match self.bump().get() {
+						/* error: just one unicode code-point given */
+						'\'' => self.recover_char_literal(LexErrorKind::InvalidUnicodeEscape),
+
+						/* valid unicode 2nd code-point given */
+						'0' ... '9' |
+						'a' ... 'f' |
+						'A' ... 'F' => match self.bump().get() {
+							/* valid closed unicode char literal */
+							'\'' => self.close_char_literal(),
+							/* error: has to close after two hex-digits */
+							_ => self.recover_char_literal(LexErrorKind::TooManyCharsInCharLiteral)
+						},
+
+						/* error: invalid 2nd code-point */
+						_ => self.recover_char_literal(LexErrorKind::InvalidUnicodeEscape)
+					},
+
+					/* invalid starting points for unicode */
+					'8' ... '9' |
+					'a' ... 'f' |
+					'A' ... 'F' => self.recover_char_literal(LexErrorKind::InvalidUnicodeEscape),
+
+					/* anything else invalid */
+					_ => self.recover_char_literal(LexErrorKind::InvalidUnicodeEscape)
+				},
+
+				/* uni-code up to 6 hex-digits (TODO) */
+				'u' => {
+					self.bump();
+					self.recover_char_literal(LexErrorKind::InvalidUnicodeEscape)
+				},
+
+				/* no valid escape character read */
+				_ => self.recover_char_literal(LexErrorKind::InvalidEscape)
+			},
+
+			/* normal ascii charater literal */
+			_ => match self.bump().get() {
+				'\'' => self.close_char_literal(),
+				/* more than one code-point in character literal */
+				_ => self.recover_char_literal(LexErrorKind::TooManyCharsInCharLiteral)
+			}
+		}
+	}
+
+	/// Recovers from a malformed string literal by consuming up to the
+	/// closing `"` or a newline, whichever comes first, and emitting
+	/// whatever was scanned so far tagged with the given diagnostic,
+	/// instead of wedging on a bare `TokenKind::Error`.
+	fn recover_string_literal(&mut self, kind: LitKind, err: LexErrorKind) -> RawToken {
+		self.consume_while(|c| c.is_none_of(&['\"', '\n', '\0']));
+		let body_end = self.len();
+		if self.get() == '\"' {
+			self.bump();
+		}
+		let suffix_start = self.len();
+		let body_start = literal_body_start(kind).min(body_end);
+		self.make_err(TokenKind::Literal { kind, body_start, body_end, suffix_start }, err)
+	}
+
+	/// Scans the escape sequence starting at the `\\` that is at the cursor.
+	/// Returns `None` on success, or the `LexErrorKind` to report if the
+	/// escape was malformed.
+	fn scan_string_escape(&mut self) -> Option<LexErrorKind> {
+		assert_eq!(self.get(), '\\');
+		match self.bump().get() {
+			/* single-character escapes */
+			'n' | 't' | 'r' | '\\' | '\"' | '\'' | '0' => {
+				self.bump();
+				None
+			},
+
+			/* '\xNN' byte escape */
+			'x' => {
+				self.bump();
+				for _ in 0 .. 2 {
+					if self.get().is_hexdec_numeral() {
+						self.bump();
+					} else {
+						return Some(LexErrorKind::InvalidUnicodeEscape);
+					}
+				}
+				None
+			},
+
+			/* '\u{...}' unicode escape, 1 to 6 hex-digits */
+			'u' => {
+				if self.bump().get() != '{' {
+					return Some(LexErrorKind::InvalidUnicodeEscape);
+				}
+				self.bump();
+
+				let mut digits = 0;
+				while digits < 6 && self.get().is_hexdec_numeral() {
+					self.bump();
+					digits += 1;
+				}
+
+				if digits == 0 || self.get() != '}' {
+					return Some(LexErrorKind::InvalidUnicodeEscape);
+				}
+				self.bump();
+				None
+			},
+
+			/* no valid escape character read */
+			_ => Some(LexErrorKind::InvalidEscape)
+		}
+	}
+
+	/// Scans a (possibly byte-) string literal body up to and including
+	/// the closing `"`, with the standard escape grammar, the cursor
+	/// already positioned on the opening `"`.
+	fn scan_quoted_string(&mut self, kind: LitKind) -> RawToken {
+		assert_eq!(self.get(), '\"');
+		self.bump();
+		loop {
+			match self.get() {
+				'\"' => {
+					let body_end = self.len();
+					self.bump();
+					let suffix_start = self.len();
+					self.scan_suffix();
+					return self.make(TokenKind::Literal {
+						kind,
+						body_start: literal_body_start(kind),
+						body_end,
+						suffix_start
+					});
+				},
+
+				'\\' => if let Some(err) = self.scan_string_escape() {
+					return self.recover_string_literal(kind, err);
+				},
+
+				/* error: newline or EOF before the closing quote */
+				'\n' | '\0' => return self.recover_string_literal(kind, LexErrorKind::UnterminatedStringLiteral),
+
+				_ => { self.bump(); }
+			}
+		}
+	}
+
+	fn scan_string_literal(&mut self) -> RawToken {
+		self.scan_quoted_string(LitKind::Str)
+	}
+
+	/// Scans a `b"..."` byte string, the cursor positioned on the `b`.
+	fn scan_byte_string(&mut self) -> RawToken {
+		assert_eq!(self.get(), 'b');
+		self.bump();
+		self.scan_quoted_string(LitKind::ByteStr)
+	}
+
+	/// Whether the `"` at the cursor closes a raw string opened with
+	/// `hashes` `#`s, i.e. it is followed by at least that many `#`s.
+	/// Consumes the closing quote and exactly `hashes` `#`s if so.
+	fn raw_string_closes(&mut self, hashes: u16) -> bool {
+		for i in 0 .. hashes {
+			if self.peek_nth(i as usize) != '#' {
+				return false;
+			}
+		}
+		self.bump();
+		for _ in 0 .. hashes {
+			self.bump();
+		}
+		true
+	}
+
+	/// Scans a raw string `r"..."`/`r#"..."#`/... (or, if `is_byte`, a raw
+	/// byte string `br"..."`/...), the cursor positioned on the `r`. The
+	/// body is taken verbatim -- no escape processing -- up to a closing
+	/// `"` followed by the same number of `#`s the opening used.
+	fn scan_raw_string(&mut self, is_byte: bool) -> RawToken {
+		assert_eq!(self.get(), 'r');
+		self.bump();
+
+		let mut hashes: u16 = 0;
+		while self.get() == '#' {
+			hashes += 1;
+			self.bump();
+		}
+
+		let kind = if is_byte { LitKind::ByteStrRaw(hashes) } else { LitKind::StrRaw(hashes) };
+
+		/* error: the hash run isn't followed by the opening '"' after all,
+		   e.g. "r#" at EOF or "r#foo" -- recover instead of wedging. */
+		if self.get() != '\"' {
+			return self.recover_string_literal(kind, LexErrorKind::UnterminatedStringLiteral);
+		}
+		self.bump();
+
+		loop {
+			match self.get() {
+				'\"' if self.raw_string_closes(hashes) => {
+					let suffix_start = self.len();
+					let body_end = suffix_start - (1 + hashes as u32);
+					self.scan_suffix();
+					return self.make(TokenKind::Literal {
+						kind,
+						body_start: literal_body_start(kind),
+						body_end,
+						suffix_start
+					});
+				},
+
+				/* error: EOF before the matching closing quote+hashes */
+				'\0' => return self.recover_string_literal(kind, LexErrorKind::UnterminatedStringLiteral),
+
+				_ => { self.bump(); }
+			}
+		}
+	}
+
+	/// Scans a raw byte string `br"..."`/`br#"..."#`/..., the cursor
+	/// positioned on the `b`.
+	fn scan_raw_byte_string(&mut self) -> RawToken {
+		assert_eq!(self.get(), 'b');
+		self.bump();
+		self.scan_raw_string(true)
+	}
+
+	/// Scans an optional literal suffix directly following a numeric,
+	/// char or string literal's body, with no intervening whitespace.
+	/// Any identifier is accepted here; whether it names a legal suffix
+	/// like `i32`/`f64` is the parser's job, not the lexer's.
+	fn scan_suffix(&mut self) {
+		if self.get().is_alpha() || self.get() == '_' {
+			self.consume_while(|c| c.is_alpha_numeral() || c == '_');
+		}
+	}
+
+	fn scan_integral_literal_suffix(&mut self) -> RawToken {
+		let suffix_start = self.len();
+		self.scan_suffix();
+		self.make(TokenKind::Literal {
+			kind: LitKind::Integer,
+			body_start: literal_body_start(LitKind::Integer),
+			body_end: suffix_start,
+			suffix_start
+		})
+	}
+
+	fn scan_binary_literal(&mut self) -> RawToken {
+		assert_eq!(self.get(), 'b');
+		self.bump();
+		self.consume_while(|c| c.is_binary_numeral() || c == '_');
+		self.scan_integral_literal_suffix()
+	}
+
+	fn scan_octal_literal(&mut self) -> RawToken {
+		assert_eq!(self.get(), 'o');
+		self.bump();
+		self.consume_while(|c| c.is_octal_numeral() || c == '_');
+		self.scan_integral_literal_suffix()
+	}
+
+	fn scan_hexdec_literal(&mut self) -> RawToken {
+		assert_eq!(self.get(), 'x');
+		self.bump();
+		self.consume_while(|c| c.is_hexdec_numeral() || c == '_');
+		self.scan_integral_literal_suffix()
+	}
+
+	fn scan_decimal_literal(&mut self) -> RawToken {
+		assert!(self.get().is_decimal_numeral() || self.get() == '_');
+		self.consume_while(|c| c.is_decimal_numeral() || c == '_');
+		match self.get() {
+			'.' => self.scan_float_literal(),
+			'e' | 'E' if self.exponent_follows() => self.scan_float_exponent(),
+			_ => self.scan_integral_literal_suffix()
+		}
+	}
+
+	fn scan_float_literal(&mut self) -> RawToken {
+		assert_eq!(self.get(), '.');
+		self.bump();
+		self.consume_while(|c| c.is_decimal_numeral() || c == '_');
+		self.scan_float_exponent()
+	}
+
+	/// Whether the `e`/`E` at the cursor starts a genuine exponent --
+	/// an optional sign followed by at least one digit -- rather than
+	/// e.g. the start of a suffix (`1ef32`) or a trailing letter with
+	/// nothing after it (`1.0e`).
+	fn exponent_follows(&self) -> bool {
+		match self.peek_nth(0) {
+			'+' | '-' => self.peek_nth(1).is_decimal_numeral(),
+			c => c.is_decimal_numeral()
+		}
+	}
+
+	/// Consumes the optional `e`/`E` exponent of a float literal (still
+	/// part of the number's value) before the literal's suffix, if any,
+	/// is scanned -- so `1e10f32` splits as value `1e10`, suffix `f32`.
+	/// Only commits to consuming it once `exponent_follows` has
+	/// confirmed a sign-then-digit(s) shape; otherwise `e`/`E` is left
+	/// for suffix/identifier scanning instead of swallowing a malformed
+	/// exponent silently.
+	fn scan_float_exponent(&mut self) -> RawToken {
+		match self.get() {
+			'e' | 'E' if self.exponent_follows() => {
+				self.bump();
+				match self.get() {
+					'+' | '-' => { self.bump(); },
+					_ => ()
+				};
+				self.consume_while(|c| c.is_decimal_numeral() || c == '_');
+			},
+			_ => ()
+		};
+
+		let suffix_start = self.len();
+		self.scan_suffix();
+		self.make(TokenKind::Literal {
+			kind: LitKind::Float,
+			body_start: literal_body_start(LitKind::Float),
+			body_end: suffix_start,
+			suffix_start
+		})
+	}
+
+	fn scan_number_literal(&mut self) -> RawToken {
+		assert!(self.get().is_decimal_numeral());
+		match self.get() {
+			'0' => match self.bump().get() {
+				'b' => self.scan_binary_literal(),
+				'o' => self.scan_octal_literal(),
+				'x' => self.scan_hexdec_literal(),
+				'.' => self.scan_float_literal(),
+				'e' | 'E' if self.exponent_follows() => self.scan_float_exponent(),
+
+				/* dec number literals cannot start with '0' */
+				'0' ... '9' | '_' => self.scan_decimal_literal(),
+
+				/* literal number suffix, e.g. 0i32 */
+				c if c.is_alpha() => self.scan_integral_literal_suffix(),
+
+				/* a bare '0' followed by anything else, e.g. '0;', '0 ', EOF */
+				_ => self.scan_integral_literal_suffix()
+			},
+
+			/* decimal number literal */
+			'1' ... '9' => self.scan_decimal_literal(),
+
+			_ => unreachable!()
+		}
+	}
+
+	/// Scans and returns the next raw token, with `len` measured from
+	/// scratch -- callers are expected to slice the original source by
+	/// that many bytes starting at their own running offset.
+	pub fn next_token(&mut self) -> RawToken {
+		self.token_start = self.cursor.pos();
+		match self.get() {
+			/* A run of whitespace becomes a single Whitespace token */
+			c if c.is_whitespace() => {
+				self.consume_while(|c| c.is_whitespace());
+				self.make(TokenKind::Whitespace)
+			},
+
+			/* Opening delimiters */
+			'(' => self.bump().make(TokenKind::OpenDelim(DelimitToken::Paren)),
+			'[' => self.bump().make(TokenKind::OpenDelim(DelimitToken::Bracket)),
+			'{' => self.bump().make(TokenKind::OpenDelim(DelimitToken::Brace)),
+
+			/* Closing delimiters */
+			')' => self.bump().make(TokenKind::CloseDelim(DelimitToken::Paren)),
+			']' => self.bump().make(TokenKind::CloseDelim(DelimitToken::Bracket)),
+			'}' => self.bump().make(TokenKind::CloseDelim(DelimitToken::Brace)),
+
+			/* Special tokens which aren't the beginning
+			   of any other token */
+			'?' => self.bump().make(TokenKind::Question),
+			';' => self.bump().make(TokenKind::SemiColon),
+			',' => self.bump().make(TokenKind::Comma),
+			'_' => self.bump().make(TokenKind::Underscore),
+
+			/* Dot, DotDot and DotDotDot tokens */
+			'.' => match self.bump().get() {
+				'.' => match self.bump().get() {
+					'.' => self.bump().make(TokenKind::DotDotDot),
+					_   => self.make(TokenKind::DotDot)
+				},
+				_ => self.make(TokenKind::Dot)
+			},
+
+			/* Tokens starting with '+' */
+			'+' => match self.bump().get() {
+				'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Plus)),
+				_   => self.make(TokenKind::BinOp(BinOpToken::Plus))
+			},
+
+			/* Tokens starting with '-' */
+			'-' => match self.bump().get() {
+				'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Minus)),
+				'>' => self.bump().make(TokenKind::Arrow),
+				_   => self.make(TokenKind::BinOp(BinOpToken::Minus))
+			},
+
+			/* Tokens starting with '*' */
+			'*' => match self.bump().get() {
+				'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Star)),
+				_   => self.make(TokenKind::BinOp(BinOpToken::Star))
+			},
+
+			/* Tokens starting with '/' */
+			'/' => match self.bump().get() {
+				'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Slash)),
+				'/' => self.scan_line_comment(),
+				'*' => self.scan_multi_line_comment(),
+				_ => self.make(TokenKind::BinOp(BinOpToken::Slash))
+			},
+
+			/* Tokens starting with '%' */
+			'%' => match self.bump().get() {
+				'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Percent)),
+				_   => self.make(TokenKind::BinOp(BinOpToken::Percent))
+			},
+
+			/* Tokens starting with '^' */
+			'^' => match self.bump().get() {
+				'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Caret)),
+				_   => self.make(TokenKind::BinOp(BinOpToken::Caret))
+			},
+
+			/* Tokens starting with '!' */
+			'!' => match self.bump().get() {
+				'=' => self.bump().make(TokenKind::RelOp(RelOpToken::NotEq)),
+				_   => self.make(TokenKind::Exclamation)
+			},
+
+			/* Tokens starting with '=' */
+			'=' => match self.bump().get() {
+				'>' => self.bump().make(TokenKind::FatArrow),
+				'=' => self.bump().make(TokenKind::RelOp(RelOpToken::EqEq)),
+				_   => self.make(TokenKind::Eq)
+			},
+
+			/* Tokens starting with '&' */
+			'&' => match self.bump().get() {
+				'&' => self.bump().make(TokenKind::LogicalOp(LogicalOpToken::AndAnd)),
+				'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::And)),
+				_   => self.make(TokenKind::BinOp(BinOpToken::And))
+			},
+
+			/* Tokens starting with '|' */
+			'|' => match self.bump().get() {
+				'|' => self.bump().make(TokenKind::LogicalOp(LogicalOpToken::OrOr)),
+				'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Or)),
+				_   => self.make(TokenKind::BinOp(BinOpToken::Or))
+			},
+
+			/* Tokens starting with '<' */
+			'<' => match self.bump().get() {
+				'<' => match self.bump().get() {
+					'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Shl)),
+					_   => self.make(TokenKind::BinOp(BinOpToken::Shl))
+				},
+				'=' => self.bump().make(TokenKind::RelOp(RelOpToken::LessEq)),
+				_   => self.make(TokenKind::RelOp(RelOpToken::LessThan))
+			},
+
+			/* Tokens starting with '>' */
+			'>' => match self.bump().get() {
+				'>' => match self.bump().get() {
+					'=' => self.bump().make(TokenKind::BinOpEq(BinOpToken::Shr)),
+					_   => self.make(TokenKind::BinOp(BinOpToken::Shr))
+				},
+				'=' => self.bump().make(TokenKind::RelOp(RelOpToken::GreaterEq)),
+				_   => self.make(TokenKind::RelOp(RelOpToken::GreaterThan))
+			},
+
+			/* Char and string literals */
+			'\'' => self.scan_char_literal(),
+			'\"' => self.scan_string_literal(),
+
+			/* 'r' and 'b' prefixes of raw- and byte-strings have to be
+			   special-cased ahead of plain identifiers, or `r"x"` would
+			   be mis-lexed as the identifier `r` followed by a string. */
+			'r' => match self.peek_nth(0) {
+				'\"' | '#' => self.scan_raw_string(false),
+				_ => self.scan_identifier()
+			},
+			'b' => match self.peek_nth(0) {
+				'\"' => self.scan_byte_string(),
+				'r' => match self.peek_nth(1) {
+					'\"' | '#' => self.scan_raw_byte_string(),
+					_ => self.scan_identifier()
+				},
+				_ => self.scan_identifier()
+			},
+
+			/* Integer- and float literals */
+			c if c.is_decimal_numeral() => self.scan_number_literal(),
+
+			/* Identifiers and keywords */
+			c if c.is_alpha() => self.scan_identifier(),
+
+			/* When end of iterator has been reached; any other
+			   unrecognized character is consumed as an Error token */
+			_ => {
+				self.cursor.bump();
+				if self.len() == 0 {
+					self.make(TokenKind::EndOfFile)
+				} else {
+					self.make(TokenKind::Error)
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,24 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Interns identifier and literal text so that equal strings seen
+/// multiple times across a compilation share a single heap allocation.
+#[derive(Default)]
+pub struct StringTable {
+	strings: RefCell<HashMap<String, Rc<String>>>
+}
+
+impl StringTable {
+	/// Returns the interned `Rc<String>` for `text`, inserting it first
+	/// if this is the first time it has been seen.
+	pub fn get_or_insert(&self, text: &str) -> Rc<String> {
+		if let Some(existing) = self.strings.borrow().get(text) {
+			return existing.clone();
+		}
+
+		let rc = Rc::new(text.to_string());
+		self.strings.borrow_mut().insert(text.to_string(), rc.clone());
+		rc
+	}
+}
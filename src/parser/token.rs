@@ -0,0 +1,121 @@
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelimitToken {
+	Paren,
+	Bracket,
+	Brace
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOpToken {
+	Plus,
+	Minus,
+	Star,
+	Slash,
+	Percent,
+	Caret,
+	And,
+	Or,
+	Shl,
+	Shr
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelOpToken {
+	NotEq,
+	EqEq,
+	LessEq,
+	LessThan,
+	GreaterEq,
+	GreaterThan
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalOpToken {
+	AndAnd,
+	OrOr
+}
+
+/// What kind of literal a `Lit` holds. The `StrRaw`/`ByteStrRaw`
+/// variants carry the number of `#` hashes the raw string opened with,
+/// so it can be round-tripped exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LitKind {
+	Bool,
+	Byte,
+	Char,
+	Integer,
+	Float,
+	Str,
+	StrRaw(u16),
+	ByteStr,
+	ByteStrRaw(u16),
+	Err
+}
+
+/// A literal token: its kind, its value text as written in the source
+/// (the `symbol`), and its optional suffix, e.g. the `u32` in `1u32` or
+/// the `f64` in `3.14f64`. The lexer accepts *any* suffix here; whether
+/// it names a legal one is left for the parser to decide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lit {
+	pub kind: LitKind,
+	pub symbol: Rc<String>,
+	pub suffix: Option<Rc<String>>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+	Whitespace,
+	Comment,
+	Error,
+	EndOfFile,
+
+	OpenDelim(DelimitToken),
+	CloseDelim(DelimitToken),
+
+	Question,
+	SemiColon,
+	Comma,
+	Underscore,
+
+	Dot,
+	DotDot,
+	DotDotDot,
+	Arrow,
+	FatArrow,
+
+	Exclamation,
+	Eq,
+
+	BinOp(BinOpToken),
+	BinOpEq(BinOpToken),
+	RelOp(RelOpToken),
+	LogicalOp(LogicalOpToken),
+
+	Identifier(Rc<String>),
+	Literal(Lit)
+}
+
+impl Token {
+	pub fn identifier_from_str(name: &str) -> Token {
+		Token::Identifier(Rc::new(name.to_string()))
+	}
+
+	pub fn integer_literal_from_str(value: &str) -> Token {
+		Token::Literal(Lit { kind: LitKind::Integer, symbol: Rc::new(value.to_string()), suffix: None })
+	}
+
+	pub fn float_literal_from_str(value: &str) -> Token {
+		Token::Literal(Lit { kind: LitKind::Float, symbol: Rc::new(value.to_string()), suffix: None })
+	}
+
+	pub fn char_literal_from_str(value: &str) -> Token {
+		Token::Literal(Lit { kind: LitKind::Char, symbol: Rc::new(value.to_string()), suffix: None })
+	}
+
+	pub fn string_literal_from_str(value: &str) -> Token {
+		Token::Literal(Lit { kind: LitKind::Str, symbol: Rc::new(value.to_string()), suffix: None })
+	}
+}
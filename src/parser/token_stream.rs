@@ -0,0 +1,7 @@
+use parser::lexer::SpannedToken;
+
+/// Anything that can be asked for the next token of a source file,
+/// one at a time.
+pub trait TokenStream {
+	fn next_token(&mut self) -> SpannedToken;
+}
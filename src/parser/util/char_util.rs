@@ -0,0 +1,38 @@
+/// Character classification used throughout the lexer. Kept separate
+/// from `std::char`'s own predicates since the lexer's notion of e.g.
+/// "alpha" deliberately excludes `_`, which callers handle on their own
+/// wherever identifiers allow it.
+pub trait CharProperties {
+	fn is_alpha(&self) -> bool;
+	fn is_alpha_numeral(&self) -> bool;
+	fn is_decimal_numeral(&self) -> bool;
+	fn is_binary_numeral(&self) -> bool;
+	fn is_octal_numeral(&self) -> bool;
+	fn is_hexdec_numeral(&self) -> bool;
+}
+
+impl CharProperties for char {
+	fn is_alpha(&self) -> bool {
+		self.is_alphabetic()
+	}
+
+	fn is_alpha_numeral(&self) -> bool {
+		self.is_alphanumeric()
+	}
+
+	fn is_decimal_numeral(&self) -> bool {
+		*self >= '0' && *self <= '9'
+	}
+
+	fn is_binary_numeral(&self) -> bool {
+		*self == '0' || *self == '1'
+	}
+
+	fn is_octal_numeral(&self) -> bool {
+		*self >= '0' && *self <= '7'
+	}
+
+	fn is_hexdec_numeral(&self) -> bool {
+		self.is_ascii_hexdigit()
+	}
+}
@@ -0,0 +1 @@
+pub mod char_util;
@@ -0,0 +1,16 @@
+/// Small convenience trait for checking a char against a set of
+/// candidates without spelling out `c == 'a' || c == 'b' || ...`.
+pub trait IsAnyOf {
+	fn is_any_of(&self, set: &[char]) -> bool;
+	fn is_none_of(&self, set: &[char]) -> bool;
+}
+
+impl IsAnyOf for char {
+	fn is_any_of(&self, set: &[char]) -> bool {
+		set.contains(self)
+	}
+
+	fn is_none_of(&self, set: &[char]) -> bool {
+		!self.is_any_of(set)
+	}
+}